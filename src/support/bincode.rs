@@ -0,0 +1,169 @@
+#![cfg(feature = "bincode")]
+//! Support for the [`bincode`](https://crates.io/crates/bincode) (v2) varint encoding.
+//!
+//! Mirrors bincode's own little-endian varint scheme for integers, extended
+//! with a marker for values wider than 128 bits: values `<= 250` are a single
+//! byte, and larger values are a marker byte followed by a fixed-width
+//! little-endian representation (251 → u16, 252 → u32, 253 → u64, 254 →
+//! u128), or, for values that don't fit in a u128, marker 255 followed by a
+//! length byte and that many significant little-endian bytes. Since the
+//! length byte is itself a single byte, this codec only supports `Uint`s
+//! whose significant-byte count fits in a `u8`, i.e. up to 255 bytes
+//! (2040 bits); encoding a wider value returns an [`EncodeError`].
+
+use crate::Uint;
+use bincode::{
+    de::{read::Reader, Decoder},
+    enc::{write::Writer, Encoder},
+    error::{DecodeError, EncodeError},
+    Decode, Encode,
+};
+
+const NON_CANONICAL: &str = "non-canonical bincode varint encoding";
+const OVERFLOW: &str = "value does not fit in Uint";
+const TOO_WIDE: &str = "value's significant byte count exceeds the 255 this codec's length byte can encode";
+
+impl<const BITS: usize, const LIMBS: usize> Encode for Uint<BITS, LIMBS> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        if let Ok(v) = u8::try_from(*self) {
+            if v <= 250 {
+                return encoder.writer().write(&[v]);
+            }
+        }
+        if let Ok(v) = u16::try_from(*self) {
+            encoder.writer().write(&[251])?;
+            return encoder.writer().write(&v.to_le_bytes());
+        }
+        if let Ok(v) = u32::try_from(*self) {
+            encoder.writer().write(&[252])?;
+            return encoder.writer().write(&v.to_le_bytes());
+        }
+        if let Ok(v) = u64::try_from(*self) {
+            encoder.writer().write(&[253])?;
+            return encoder.writer().write(&v.to_le_bytes());
+        }
+        if let Ok(v) = u128::try_from(*self) {
+            encoder.writer().write(&[254])?;
+            return encoder.writer().write(&v.to_le_bytes());
+        }
+        let len = self.byte_len();
+        let len = u8::try_from(len).map_err(|_| EncodeError::Other(TOO_WIDE))?;
+        encoder.writer().write(&[255])?;
+        encoder.writer().write(&[len])?;
+        encoder.writer().write(&self.as_le_bytes()[..usize::from(len)])
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decode for Uint<BITS, LIMBS> {
+    fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let mut marker = [0u8; 1];
+        decoder.reader().read(&mut marker)?;
+        match marker[0] {
+            marker @ 0..=250 => {
+                Self::try_from(marker).map_err(|_| DecodeError::OtherString(OVERFLOW.to_string()))
+            }
+            251 => {
+                let v = u16::from_le_bytes(read_array(decoder.reader())?);
+                if v <= 250 {
+                    return Err(DecodeError::OtherString(NON_CANONICAL.to_string()));
+                }
+                Self::try_from(v).map_err(|_| DecodeError::OtherString(OVERFLOW.to_string()))
+            }
+            252 => {
+                let v = u32::from_le_bytes(read_array(decoder.reader())?);
+                if v <= u32::from(u16::MAX) {
+                    return Err(DecodeError::OtherString(NON_CANONICAL.to_string()));
+                }
+                Self::try_from(v).map_err(|_| DecodeError::OtherString(OVERFLOW.to_string()))
+            }
+            253 => {
+                let v = u64::from_le_bytes(read_array(decoder.reader())?);
+                if v <= u64::from(u32::MAX) {
+                    return Err(DecodeError::OtherString(NON_CANONICAL.to_string()));
+                }
+                Self::try_from(v).map_err(|_| DecodeError::OtherString(OVERFLOW.to_string()))
+            }
+            254 => {
+                let v = u128::from_le_bytes(read_array(decoder.reader())?);
+                if v <= u128::from(u64::MAX) {
+                    return Err(DecodeError::OtherString(NON_CANONICAL.to_string()));
+                }
+                Self::try_from(v).map_err(|_| DecodeError::OtherString(OVERFLOW.to_string()))
+            }
+            255 => {
+                let mut len = [0u8; 1];
+                decoder.reader().read(&mut len)?;
+                let len = usize::from(len[0]);
+                if len <= 16 {
+                    return Err(DecodeError::OtherString(NON_CANONICAL.to_string()));
+                }
+                let mut bytes = vec![0u8; len];
+                decoder.reader().read(&mut bytes)?;
+                if bytes[len - 1] == 0 {
+                    return Err(DecodeError::OtherString(NON_CANONICAL.to_string()));
+                }
+                Self::try_from_le_slice(&bytes).ok_or(DecodeError::OtherString(OVERFLOW.to_string()))
+            }
+        }
+    }
+}
+
+fn read_array<const N: usize, R: Reader>(reader: &mut R) -> Result<[u8; N], DecodeError> {
+    let mut bytes = [0u8; N];
+    reader.read(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs, Uint};
+    use bincode::config;
+    use proptest::proptest;
+
+    fn encode<const BITS: usize, const LIMBS: usize>(value: Uint<BITS, LIMBS>) -> Vec<u8> {
+        bincode::encode_to_vec(value, config::standard()).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                if value.byte_len() <= 255 {
+                    let serialized = encode(value);
+                    let (deserialized, len): (Uint<BITS, LIMBS>, usize) =
+                        bincode::decode_from_slice(&serialized, config::standard()).unwrap();
+                    assert_eq!(len, serialized.len());
+                    assert_eq!(value, deserialized);
+                } else {
+                    // More significant bytes than the length byte can address.
+                    assert!(bincode::encode_to_vec(value, config::standard()).is_err());
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_too_wide_value_is_rejected() {
+        // 2048 bits = 256 significant bytes, one more than the length byte
+        // can address.
+        let value = Uint::<2048, 32>::MAX;
+        let result = bincode::encode_to_vec(value, config::standard());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_small_values_are_one_byte() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                if let Ok(v) = u8::try_from(value) {
+                    if v <= 250 {
+                        assert_eq!(encode(value).len(), 1);
+                    }
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "bitcoin")]
+//! Support for Bitcoin consensus serialization.
+//!
+//! Implements the `CompactSize` (a.k.a. `VarInt`) encoding used throughout
+//! the Bitcoin P2P and transaction formats, plus a fixed-width big-endian
+//! encoding for hash-like 256-bit values.
+//!
+//! See <https://developer.bitcoin.org/reference/transactions.html#compactsize-unsigned-integers>
+
+use crate::Uint;
+use bitcoin::consensus::encode::{self, Decodable, Encodable};
+use std::io;
+
+const TOO_WIDE: &str = "value exceeds the 64-bit ceiling of CompactSize encoding";
+
+/// Allows a [`Uint`] to be serialized as a Bitcoin `CompactSize`, as long as
+/// the value itself fits in 64 bits (regardless of its `BITS` type parameter).
+///
+/// See <https://developer.bitcoin.org/reference/transactions.html#compactsize-unsigned-integers>
+impl<const BITS: usize, const LIMBS: usize> Encodable for Uint<BITS, LIMBS> {
+    fn consensus_encode<W: io::Write + ?Sized>(&self, writer: &mut W) -> Result<usize, io::Error> {
+        let value = self
+            .checked_to::<u64>()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, TOO_WIDE))?;
+        match value {
+            0..=0xFC => {
+                #[allow(clippy::cast_possible_truncation)] // value <= 0xFC
+                writer.write_all(&[value as u8])?;
+                Ok(1)
+            }
+            0xFD..=0xFFFF => {
+                writer.write_all(&[0xFD])?;
+                #[allow(clippy::cast_possible_truncation)] // value <= 0xFFFF
+                writer.write_all(&(value as u16).to_le_bytes())?;
+                Ok(3)
+            }
+            0x1_0000..=0xFFFF_FFFF => {
+                writer.write_all(&[0xFE])?;
+                #[allow(clippy::cast_possible_truncation)] // value <= 0xFFFF_FFFF
+                writer.write_all(&(value as u32).to_le_bytes())?;
+                Ok(5)
+            }
+            _ => {
+                writer.write_all(&[0xFF])?;
+                writer.write_all(&value.to_le_bytes())?;
+                Ok(9)
+            }
+        }
+    }
+}
+
+/// Allows a [`Uint`] to be deserialized from a Bitcoin `CompactSize`,
+/// rejecting non-minimal (non-canonical) encodings per consensus rules.
+///
+/// See <https://developer.bitcoin.org/reference/transactions.html#compactsize-unsigned-integers>
+impl<const BITS: usize, const LIMBS: usize> Decodable for Uint<BITS, LIMBS> {
+    fn consensus_decode<R: io::Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        let mut prefix = [0u8; 1];
+        reader.read_exact(&mut prefix)?;
+        let value = match prefix[0] {
+            0xFF => {
+                let mut bytes = [0u8; 8];
+                reader.read_exact(&mut bytes)?;
+                let value = u64::from_le_bytes(bytes);
+                if value <= u64::from(u32::MAX) {
+                    return Err(encode::Error::NonMinimalVarInt);
+                }
+                value
+            }
+            0xFE => {
+                let mut bytes = [0u8; 4];
+                reader.read_exact(&mut bytes)?;
+                let value = u32::from_le_bytes(bytes);
+                if value <= u32::from(u16::MAX) {
+                    return Err(encode::Error::NonMinimalVarInt);
+                }
+                u64::from(value)
+            }
+            0xFD => {
+                let mut bytes = [0u8; 2];
+                reader.read_exact(&mut bytes)?;
+                let value = u16::from_le_bytes(bytes);
+                if value < 0xFD {
+                    return Err(encode::Error::NonMinimalVarInt);
+                }
+                u64::from(value)
+            }
+            byte => u64::from(byte),
+        };
+        Self::try_from(value).map_err(|_| encode::Error::ParseFailed("value does not fit in Uint"))
+    }
+}
+
+/// Wraps a [`Uint`] so it serializes as a fixed-width big-endian value,
+/// matching Bitcoin's treatment of 256-bit hashes.
+pub struct ConsensusHash<const BITS: usize, const LIMBS: usize>(pub Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> Encodable for ConsensusHash<BITS, LIMBS> {
+    fn consensus_encode<W: io::Write + ?Sized>(&self, writer: &mut W) -> Result<usize, io::Error> {
+        let bytes = self.0.to_be_bytes_vec();
+        writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Decodable for ConsensusHash<BITS, LIMBS> {
+    fn consensus_decode<R: io::Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        let mut bytes = vec![0u8; Uint::<BITS, LIMBS>::BYTES];
+        reader.read_exact(&mut bytes)?;
+        Uint::try_from_be_slice(&bytes)
+            .map(Self)
+            .ok_or(encode::Error::ParseFailed("value does not fit in Uint"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs, Uint};
+    use proptest::proptest;
+
+    fn encode<T: Encodable>(value: &T) -> Vec<u8> {
+        let mut buf = vec![];
+        value.consensus_encode(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_compact_size_boundaries() {
+        assert_eq!(encode(&Uint::<64, 1>::from(0xFC)), [0xFC]);
+        assert_eq!(encode(&Uint::<64, 1>::from(0xFD)), [0xFD, 0xFD, 0x00]);
+        assert_eq!(encode(&Uint::<64, 1>::from(0xFFFF)), [0xFD, 0xFF, 0xFF]);
+        assert_eq!(
+            encode(&Uint::<64, 1>::from(0x1_0000)),
+            [0xFE, 0x00, 0x00, 0x01, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_compact_size_rejects_non_canonical() {
+        // 0xFD prefix with a payload that fits in a single byte.
+        let bytes = [0xFD, 0xFC, 0x00];
+        assert!(Uint::<64, 1>::consensus_decode(&mut &bytes[..]).is_err());
+        // 0xFE prefix with a payload that fits in a u16.
+        let bytes = [0xFE, 0xFF, 0xFF, 0x00, 0x00];
+        assert!(Uint::<64, 1>::consensus_decode(&mut &bytes[..]).is_err());
+        // 0xFF prefix with a payload that fits in a u32.
+        let bytes = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00];
+        assert!(Uint::<64, 1>::consensus_decode(&mut &bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_compact_size_rejects_truncated_input() {
+        assert!(Uint::<64, 1>::consensus_decode(&mut &[][..]).is_err());
+        // 0xFD prefix with no trailing u16 payload.
+        assert!(Uint::<64, 1>::consensus_decode(&mut &[0xFD][..]).is_err());
+        assert!(Uint::<64, 1>::consensus_decode(&mut &[0xFD, 0xFF][..]).is_err());
+        // 0xFF prefix with a truncated u64 payload.
+        assert!(Uint::<64, 1>::consensus_decode(&mut &[0xFF, 0xFF, 0xFF][..]).is_err());
+    }
+
+    #[test]
+    fn test_compact_size_encode_rejects_values_too_wide_for_u64() {
+        let value = Uint::<128, 2>::from(u64::MAX) + Uint::<128, 2>::from(1);
+        let mut buf = vec![];
+        assert!(value.consensus_encode(&mut buf).is_err());
+    }
+
+    #[test]
+    fn test_compact_size_encode_allows_small_values_in_wide_uint() {
+        assert_eq!(encode(&Uint::<128, 2>::from(5)), [5]);
+    }
+
+    #[test]
+    fn test_compact_size_roundtrip() {
+        const_for!(BITS in SIZES {
+            if BITS <= 64 {
+                const LIMBS: usize = nlimbs(BITS);
+                proptest!(|(value: Uint<BITS, LIMBS>)| {
+                    let serialized = encode(&value);
+                    let deserialized = Uint::<BITS, LIMBS>::consensus_decode(&mut &serialized[..]).unwrap();
+                    assert_eq!(value, deserialized);
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn test_consensus_hash_roundtrip() {
+        proptest!(|(value: U256)| {
+            let serialized = encode(&ConsensusHash(value));
+            assert_eq!(serialized.len(), U256::BYTES);
+            let deserialized = ConsensusHash::<256, 4>::consensus_decode(&mut &serialized[..]).unwrap();
+            assert_eq!(value, deserialized.0);
+        });
+    }
+}
@@ -7,19 +7,19 @@ use fastrlp::{BufMut, Decodable, DecodeError, Encodable, Header};
 /// Allows a [`Uint`] to be serialized as RLP.
 ///
 /// See <https://eth.wiki/en/fundamentals/rlp>
-// OPT: Implement `length()` using `leading_zeros()`.
 impl<const BITS: usize, const LIMBS: usize> Encodable for Uint<BITS, LIMBS> {
     fn encode(&self, out: &mut dyn BufMut) {
-        let bytes = self.to_be_bytes_vec();
-        // Strip most-significant zeros.
-        let bytes = trim_leading_zeros(&bytes);
-        match bytes.len() {
+        let n = self.byte_len();
+        match n {
             0 => out.put_u8(0x80),
-            1 if bytes[0] <= 0x7f => out.put_u8(bytes[0]),
+            1 if self.as_limbs()[0] <= 0x7f => {
+                #[allow(clippy::cast_possible_truncation)] // self.as_limbs()[0] <= 0x7f
+                out.put_u8(self.as_limbs()[0] as u8);
+            }
             n if n <= 55 => {
                 #[allow(clippy::cast_possible_truncation)] // n < 56 < 256
                 out.put_u8(0x80 + n as u8);
-                out.put_slice(bytes);
+                put_be_bytes(self, out);
             }
             n => {
                 let length_bytes = n.to_be_bytes();
@@ -27,7 +27,42 @@ impl<const BITS: usize, const LIMBS: usize> Encodable for Uint<BITS, LIMBS> {
                 #[allow(clippy::cast_possible_truncation)] // length_bytes.len() <= 8
                 out.put_u8(0xb7 + length_bytes.len() as u8);
                 out.put_slice(length_bytes);
-                out.put_slice(bytes);
+                put_be_bytes(self, out);
+            }
+        }
+    }
+
+    fn length(&self) -> usize {
+        let n = self.byte_len();
+        match n {
+            0 => 1,
+            1 if self.as_limbs()[0] <= 0x7f => 1,
+            n if n <= 55 => 1 + n,
+            n => {
+                let length_bytes = n.to_be_bytes();
+                let length_bytes = trim_leading_zeros(&length_bytes);
+                1 + length_bytes.len() + n
+            }
+        }
+    }
+}
+
+/// Streams the big-endian significant bytes of `value` into `out`, limb by
+/// limb, without ever collecting them into a heap buffer.
+fn put_be_bytes<const BITS: usize, const LIMBS: usize>(
+    value: &Uint<BITS, LIMBS>,
+    out: &mut dyn BufMut,
+) {
+    let mut started = false;
+    for &limb in value.as_limbs().iter().rev() {
+        let bytes = limb.to_be_bytes();
+        if started {
+            out.put_slice(&bytes);
+        } else {
+            let trimmed = trim_leading_zeros(&bytes);
+            if !trimmed.is_empty() {
+                out.put_slice(trimmed);
+                started = true;
             }
         }
     }
@@ -93,6 +128,16 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_length() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                assert_eq!(value.length(), encode(value).len());
+            });
+        });
+    }
+
     #[test]
     #[cfg(feature = "rlp")]
     fn test_rlp_fastrlp_compat() {
@@ -0,0 +1,67 @@
+#![cfg(feature = "reth-codecs")]
+//! Support for the [`reth-codecs`](https://crates.io/crates/reth-codecs) `Compact` trait.
+
+use crate::Uint;
+use bytes::BufMut;
+use reth_codecs::Compact;
+
+/// Allows a [`Uint`] to be stored in reth's database using its
+/// length-out-of-band `Compact` convention: only the significant
+/// little-endian bytes are written, and the byte count is returned so the
+/// caller can persist it separately (e.g. in a bitfield).
+impl<const BITS: usize, const LIMBS: usize> Compact for Uint<BITS, LIMBS> {
+    fn to_compact<B: BufMut + AsMut<[u8]>>(self, buf: &mut B) -> usize {
+        let bytes = self.as_le_bytes();
+        let len = self.byte_len();
+        buf.put_slice(&bytes[..len]);
+        len
+    }
+
+    fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+        let value = Self::try_from_le_slice(&buf[..len]).expect("value does not fit in Uint");
+        (value, &buf[len..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs, Uint};
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let mut buf = vec![];
+                let len = value.to_compact(&mut buf);
+                let (decoded, rest) = Uint::<BITS, LIMBS>::from_compact(&buf, len);
+                assert_eq!(decoded, value);
+                assert_eq!(rest.len(), 0);
+            });
+        });
+    }
+
+    #[test]
+    fn test_length_matches_byte_len() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let mut buf = vec![];
+                let len = value.to_compact(&mut buf);
+                assert_eq!(len, value.byte_len());
+            });
+        });
+    }
+
+    #[test]
+    fn test_zero_encodes_to_no_bytes() {
+        let mut buf = vec![];
+        let len = Uint::<256, 4>::ZERO.to_compact(&mut buf);
+        assert_eq!(len, 0);
+        let (decoded, rest) = Uint::<256, 4>::from_compact(&buf, 0);
+        assert_eq!(decoded, Uint::<256, 4>::ZERO);
+        assert_eq!(rest.len(), 0);
+    }
+}
@@ -3,12 +3,20 @@
 #![cfg_attr(has_doc_cfg, doc(cfg(feature = "parity-scale-codec")))]
 
 use crate::Uint;
-use parity_scale_codec::{Compact, CompactAs, Decode, Encode, Error, Input, MaxEncodedLen, Output};
+use parity_scale_codec::{
+    Compact, CompactAs, Decode, Encode, EncodeAsRef, Error, HasCompact, Input, MaxEncodedLen,
+    Output,
+};
 
 // Compact encoding is supported only for 0-(2**536-1) values:
 // https://docs.substrate.io/reference/scale-codec/#fn-1
 pub(crate) const COMPACT_BITS_LIMIT: usize = 536;
 
+// Largest payload, in bytes, the "big integer" compact mode can carry
+// (`COMPACT_BITS_LIMIT` rounded up to a whole byte), plus one byte of
+// headroom so the stack buffer below never needs to be sized exactly.
+const MAX_COMPACT_BYTES: usize = (COMPACT_BITS_LIMIT + 7) / 8 + 1;
+
 impl<const BITS: usize, const LIMBS: usize> Encode for Uint<BITS, LIMBS> {
     /// u32 prefix for compact encoding + bytes needed for LE bytes representation
     fn size_hint(&self) -> usize {
@@ -55,8 +63,42 @@ impl<const BITS: usize, const LIMBS: usize> CompactAs for CompactUint<BITS, LIMB
     }
 }
 
+/// Lets a bare [`Uint`] field be declared `#[codec(compact)]` in a derived
+/// struct, the same way primitive integers can: the derive machinery picks
+/// [`CompactRefUint`] as the reference encoder and decodes through
+/// [`CompactUint`].
+impl<const BITS: usize, const LIMBS: usize> HasCompact for Uint<BITS, LIMBS> {
+    type Type = CompactUint<BITS, LIMBS>;
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for CompactUint<BITS, LIMBS> {
+    fn from(v: Uint<BITS, LIMBS>) -> Self {
+        Self(v)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<CompactUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    fn from(v: CompactUint<BITS, LIMBS>) -> Self {
+        v.0
+    }
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize> EncodeAsRef<'a, Uint<BITS, LIMBS>>
+    for CompactUint<BITS, LIMBS>
+{
+    type RefType = CompactRefUint<'a, BITS, LIMBS>;
+}
+
 pub struct CompactRefUint<'a, const BITS: usize, const LIMBS: usize>(pub &'a Uint<BITS, LIMBS>);
 
+impl<'a, const BITS: usize, const LIMBS: usize> From<&'a Uint<BITS, LIMBS>>
+    for CompactRefUint<'a, BITS, LIMBS>
+{
+    fn from(v: &'a Uint<BITS, LIMBS>) -> Self {
+        Self(v)
+    }
+}
+
 impl<'a, const BITS: usize, const LIMBS: usize> Encode for CompactRefUint<'a, BITS, LIMBS> {
     fn size_hint(&self) -> usize {
         match self.0.trailing_ones() {
@@ -176,30 +218,19 @@ impl<const BITS: usize, const LIMBS: usize> Decode for CompactUint<BITS, LIMBS>
                     }
                 }
                 bytes => {
-                    let le_byte_slice = (0..bytes)
-                        .map(|_| input.read_byte())
-                        .rev()
-                        .collect::<Result<Vec<_>, _>>()?;
-                    let x = Uint::<BITS, LIMBS>::try_from_le_slice(&le_byte_slice)
-                        .ok_or(Error::from("value is larger than fits the Uint"))?;
-                    let bits = bytes as usize * 8;
-                    let limbs = (bits + 64 - 1) / 64;
-
-                    let mut new_limbs = vec![u64::MAX; limbs];
-                    if bits > 0 {
-                        new_limbs[limbs - 1] &= if bits % 64 == 0 {
-                            u64::MAX
-                        } else {
-                            (1 << bits % 64) - 1
-                        }
+                    let len = bytes as usize;
+                    let mut le_bytes = [0u8; MAX_COMPACT_BYTES];
+                    for byte in &mut le_bytes[..len] {
+                        *byte = input.read_byte()?;
                     }
-                    if Uint::<COMPACT_BITS_LIMIT, 9>::from(x)
-                        > Uint::from_limbs_slice(&new_limbs) >> ((68 - bytes as usize + 1) * 8)
-                    {
-                        x
-                    } else {
+                    let x = Uint::<BITS, LIMBS>::try_from_le_slice(&le_bytes[..len])
+                        .ok_or(Error::from("value is larger than fits the Uint"))?;
+                    // Canonical encodings use exactly as many bytes as the
+                    // value needs; reject anything padded with extra bytes.
+                    if x.byte_len() < len {
                         return Err(OUT_OF_RANGE.into());
                     }
+                    x
                 }
             },
         }))
@@ -250,4 +281,56 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_scale_compact_derive() {
+        #[derive(Encode, Decode, PartialEq, Debug)]
+        struct Foo {
+            #[codec(compact)]
+            value: Uint<256, 4>,
+        }
+
+        proptest!(|(value: Uint<256, 4>)| {
+            let foo = Foo { value };
+            let serialized = foo.encode();
+            let deserialized = Foo::decode(&mut serialized.as_slice()).unwrap();
+            assert_eq!(foo, deserialized);
+        });
+    }
+
+    /// An [`Input`] over a byte slice that performs no heap allocations of
+    /// its own, used to round-trip compact values through a non-allocating
+    /// input. It does not detect allocations made by the decoder itself.
+    struct NoAllocInput<'a>(&'a [u8]);
+
+    impl<'a> parity_scale_codec::Input for NoAllocInput<'a> {
+        fn remaining_len(&mut self) -> Result<Option<usize>, parity_scale_codec::Error> {
+            Ok(Some(self.0.len()))
+        }
+
+        fn read(&mut self, into: &mut [u8]) -> Result<(), parity_scale_codec::Error> {
+            if into.len() > self.0.len() {
+                return Err("not enough data to fill buffer".into());
+            }
+            let (head, tail) = self.0.split_at(into.len());
+            into.copy_from_slice(head);
+            self.0 = tail;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_scale_compact_no_alloc_input() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                if BITS < COMPACT_BITS_LIMIT {
+                    let serialized_compact = CompactRefUint(&value).encode();
+                    let deserialized_compact =
+                        CompactUint::decode(&mut NoAllocInput(&serialized_compact)).unwrap();
+                    assert_eq!(value, deserialized_compact.0);
+                }
+            });
+        });
+    }
 }